@@ -64,34 +64,46 @@
 //! options.choice_algorithm = Some("fast".into());
 //! options.information_level = Some("0".into());
 //!
-//! let reports = policy.run(
+//! let result = policy.run(
 //!     "/data/test",
 //!     "/work/.policy/size.policy",
 //!     Some(Path::new("/work/.policy/report")),
 //!     &options
 //! )?;
 //!
-//! // for report in reports {
-//!     // parse the file manually
-//!     // this library does not yet provide a way to do this
-//! // }
+//! for report in result.reports {
+//!     for record in Report::open(report)? {
+//!         let record = record?;
+//!         println!("{}", record.path.display());
+//!     }
+//! }
 //! # Ok(())
 //! # }
 //! ```
 
-#![forbid(unsafe_code)]
+// `resolve` needs a small, narrowly-scoped amount of `unsafe` to call
+// `getpwnam_r`/`getgrnam_r`, so this is `deny` rather than `forbid`.
+#![deny(unsafe_code)]
 #![deny(missing_docs)]
 #![deny(clippy::all)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::cargo)]
 
 #[cfg(feature = "clap")]
 pub mod clap;
+mod parse;
+mod report;
+mod resolve;
 mod run;
 mod types;
 mod write;
 
 /// The important stuff.
 pub mod prelude {
-    pub use crate::run::Options as RunOptions;
+    pub use crate::parse::{ParseError, ParseErrorKind, ReadError};
+    pub use crate::report::{Error as ReportError, Record as ReportRecord, Report, ShowValue};
+    pub use crate::resolve::Error as ResolveError;
+    pub use crate::run::{
+        LineCallback, OutputMode, Options as RunOptions, RunOutput,
+    };
     pub use crate::types::*;
 }
@@ -0,0 +1,884 @@
+use std::io::{self, Read};
+
+use crate::types::{
+    Attribute, DirectoriesPlus, Exec, Expr, FromPool, Literal, Name, Policy, Rule, RuleType, Show,
+    Threshold, ToPool, Weight,
+};
+
+/// Error encountered while parsing a `.policy` file.
+#[derive(Debug)]
+pub struct ParseError {
+    /// 1-based line the error occurred on.
+    pub line: usize,
+
+    /// 1-based column the error occurred on.
+    pub column: usize,
+
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Kinds of [`ParseError`].
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// Input ended before a complete rule could be parsed.
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+
+    /// A token other than the one(s) expected was found.
+    #[error("expected {expected}, found `{found}`")]
+    Expected {
+        /// What the parser was looking for.
+        expected: &'static str,
+
+        /// What it found instead.
+        found: String,
+    },
+
+    /// A `SHOW(...)` list referenced an attribute this crate does not know.
+    #[error("unknown SHOW token `{0}`")]
+    UnknownShow(String),
+
+    /// A `RULE` used a keyword this crate does not know.
+    #[error("unknown rule keyword `{0}`")]
+    UnknownRuleKeyword(String),
+
+    /// One or more recoverable errors were found while parsing.
+    #[error("{} error(s) while parsing policy", .0.len())]
+    Many(Vec<ParseError>),
+}
+
+type Result<T> = ::std::result::Result<T, ParseError>;
+
+/// Error from [`Policy::parse_reader`].
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ReadError {
+    /// Reading from the input failed.
+    #[error("reading policy: {0}")]
+    Io(#[from] io::Error),
+
+    /// The input was not valid policy syntax.
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+impl Policy {
+    /// Parse a policy from its `.policy` text representation.
+    ///
+    /// This is the inverse of [`Policy::write`]: the rules written by
+    /// `write` can be read back with `parse`. Note that `write` never
+    /// emits the policy's own [`Name`], so a parsed policy always has an
+    /// empty name; set `policy.name` afterwards if you need one.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] when `input` is not valid policy syntax.
+    /// Unknown `SHOW` tokens and unknown rule keywords are recoverable and
+    /// are collected into a single [`ParseErrorKind::Many`] instead of
+    /// aborting on the first one.
+    pub fn parse(input: &str) -> Result<Self> {
+        Parser::new(input).parse_policy()
+    }
+
+    /// Parse a policy read from `input`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReadError`] if `input` cannot be read, or if its
+    /// contents are not valid policy syntax.
+    pub fn parse_reader(
+        input: &mut impl Read,
+    ) -> ::std::result::Result<Self, ReadError> {
+        let mut buf = String::new();
+        input.read_to_string(&mut buf)?;
+        Ok(Self::parse(&buf)?)
+    }
+}
+
+/// Hand-written recursive-descent parser for the small policy grammar.
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    fn new(input: &str) -> Self {
+        Self {
+            chars: input.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.pos += 1;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    const fn mark(&self) -> (usize, usize, usize) {
+        (self.pos, self.line, self.column)
+    }
+
+    const fn reset(&mut self, mark: (usize, usize, usize)) {
+        (self.pos, self.line, self.column) = mark;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.advance();
+        }
+    }
+
+    const fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    /// Describes the next token for an error message, without consuming
+    /// it. Captures the whole identifier when one starts here, so that a
+    /// bad keyword like `BOGUS` is reported as one token rather than as
+    /// its first character.
+    fn describe_next(&mut self) -> String {
+        self.skip_whitespace();
+
+        let mark = self.mark();
+
+        if matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            if let Ok(ident) = self.parse_ident() {
+                self.reset(mark);
+                return ident;
+            }
+        }
+
+        self.peek()
+            .map_or_else(|| "<eof>".to_string(), |c| c.to_string())
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+
+        match self.advance() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(ParseErrorKind::Expected {
+                expected: "a character",
+                found: c.to_string(),
+            })),
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn parse_ident(&mut self) -> Result<String> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            self.advance();
+        }
+
+        if self.pos == start {
+            let found = self.describe_next();
+            return Err(self.error(ParseErrorKind::Expected {
+                expected: "an identifier",
+                found,
+            }));
+        }
+
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.skip_whitespace();
+        self.expect_char('\'')?;
+
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(c) if c != '\'') {
+            self.advance();
+        }
+
+        let s: String = self.chars[start..self.pos].iter().collect();
+
+        self.expect_char('\'')?;
+
+        Ok(s)
+    }
+
+    fn parse_number<T: std::str::FromStr>(&mut self) -> Result<T> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+
+        if matches!(self.peek(), Some('-')) {
+            self.advance();
+        }
+
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.advance();
+        }
+
+        let s: String = self.chars[start..self.pos].iter().collect();
+
+        s.parse().map_err(|_| {
+            self.error(ParseErrorKind::Expected {
+                expected: "a number",
+                found: s,
+            })
+        })
+    }
+
+    /// Consumes and returns `true` if the next identifier is `kw`, otherwise
+    /// leaves the parser position untouched and returns `false`.
+    fn try_keyword(&mut self, kw: &str) -> bool {
+        let mark = self.mark();
+
+        if let Ok(ident) = self.parse_ident() {
+            if ident == kw {
+                return true;
+            }
+        }
+
+        self.reset(mark);
+        false
+    }
+
+    fn expect_keyword(&mut self, kw: &'static str) -> Result<()> {
+        if self.try_keyword(kw) {
+            Ok(())
+        } else {
+            let found = self.describe_next();
+            Err(self.error(ParseErrorKind::Expected {
+                expected: kw,
+                found,
+            }))
+        }
+    }
+
+    /// Consumes and returns a quoted string if one follows, without
+    /// consuming anything otherwise.
+    fn try_string(&mut self) -> Option<String> {
+        let mark = self.mark();
+        self.skip_whitespace();
+
+        if self.peek() == Some('\'') {
+            if let Ok(s) = self.parse_string() {
+                return Some(s);
+            }
+        }
+
+        self.reset(mark);
+        None
+    }
+
+    /// `||`
+    fn expect_concat(&mut self) -> Result<()> {
+        self.expect_char('|')?;
+        self.expect_char('|')
+    }
+
+    fn at_eof(&mut self) -> bool {
+        self.skip_whitespace();
+        self.peek().is_none()
+    }
+
+    /// Recovers from a rule-level parse failure by skipping ahead to the
+    /// next `RULE` keyword (or EOF), so one bad rule is reported once
+    /// instead of cascading into an error per leftover character.
+    fn skip_to_next_rule(&mut self) {
+        while !self.at_eof() {
+            let mark = self.mark();
+
+            if self.try_keyword("RULE") {
+                self.reset(mark);
+                return;
+            }
+
+            self.advance();
+        }
+    }
+
+    fn parse_policy(mut self) -> Result<Policy> {
+        let mut rules = Vec::new();
+        let mut errors = Vec::new();
+
+        while !self.at_eof() {
+            match self.parse_rule() {
+                Ok(rule) => rules.push(rule),
+                Err(error) => {
+                    errors.push(error);
+                    self.skip_to_next_rule();
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Policy {
+                name: Name(String::new()),
+                rules,
+            })
+        } else {
+            Err(ParseError {
+                line: 1,
+                column: 1,
+                kind: ParseErrorKind::Many(errors),
+            })
+        }
+    }
+
+    fn parse_rule(&mut self) -> Result<Rule> {
+        self.expect_keyword("RULE")?;
+
+        let name = self.try_string();
+        let rule_type = self.parse_rule_type()?;
+
+        Ok(Rule(name.map(Name), rule_type))
+    }
+
+    fn parse_rule_type(&mut self) -> Result<RuleType> {
+        if self.try_keyword("EXTERNAL") {
+            self.expect_keyword("LIST")?;
+            let name = self.parse_string()?;
+            self.expect_keyword("EXEC")?;
+            let exec = self.parse_string()?;
+
+            return Ok(RuleType::ExternalList(Name(name), Exec(exec)));
+        }
+
+        if self.try_keyword("LIST") {
+            let name = self.parse_string()?;
+            let directories_plus = self.try_keyword("DIRECTORIES_PLUS");
+
+            let show = if self.try_keyword("SHOW") {
+                self.parse_show_list()?
+            } else {
+                Vec::new()
+            };
+
+            let filter = self.try_where()?;
+
+            return Ok(RuleType::List(
+                Name(name),
+                DirectoriesPlus(directories_plus),
+                show,
+                filter,
+            ));
+        }
+
+        if self.try_keyword("MIGRATE") {
+            self.expect_keyword("FROM")?;
+            self.expect_keyword("POOL")?;
+            let from = self.parse_string()?;
+
+            self.expect_keyword("TO")?;
+            self.expect_keyword("POOL")?;
+            let to = self.parse_string()?;
+
+            let threshold = self.try_threshold()?;
+            let weight = self.try_weight_clause()?;
+            let filter = self.try_where()?;
+
+            return Ok(RuleType::Migrate(
+                FromPool(from),
+                ToPool(to),
+                threshold,
+                weight,
+                filter,
+            ));
+        }
+
+        if self.try_keyword("DELETE") {
+            let threshold = self.try_threshold()?;
+            let weight = self.try_weight_clause()?;
+            let filter = self.try_where()?;
+
+            return Ok(RuleType::Delete(threshold, weight, filter));
+        }
+
+        if self.try_keyword("EXCLUDE") {
+            let filter = self.try_where()?;
+
+            return Ok(RuleType::Exclude(filter));
+        }
+
+        let found = self.describe_next();
+        Err(self.error(ParseErrorKind::UnknownRuleKeyword(found)))
+    }
+
+    /// `THRESHOLD(high,low[,premigrate])`
+    fn try_threshold(&mut self) -> Result<Option<Threshold>> {
+        if !self.try_keyword("THRESHOLD") {
+            return Ok(None);
+        }
+
+        self.expect_char('(')?;
+        let high = self.parse_number()?;
+        self.expect_char(',')?;
+        let low = self.parse_number()?;
+
+        let premigrate = if self.try_char(',') {
+            Some(self.parse_number()?)
+        } else {
+            None
+        };
+
+        self.expect_char(')')?;
+
+        Ok(Some(Threshold {
+            high,
+            low,
+            premigrate,
+        }))
+    }
+
+    /// `WEIGHT(...)`
+    fn try_weight_clause(&mut self) -> Result<Option<Weight>> {
+        if !self.try_keyword("WEIGHT") {
+            return Ok(None);
+        }
+
+        self.expect_char('(')?;
+        let weight = self.parse_weight()?;
+        self.expect_char(')')?;
+
+        Ok(Some(weight))
+    }
+
+    /// `({0} OP {1})`, `DAYS({0})`, an attribute, or a numeric literal.
+    fn parse_weight(&mut self) -> Result<Weight> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('(') {
+            self.advance();
+            let lhs = self.parse_weight()?;
+            let op = self.parse_weight_op()?;
+            let rhs = self.parse_weight()?;
+            self.expect_char(')')?;
+
+            return Ok(match op {
+                '+' => Weight::Add(Box::new(lhs), Box::new(rhs)),
+                '-' => Weight::Sub(Box::new(lhs), Box::new(rhs)),
+                '*' => Weight::Mul(Box::new(lhs), Box::new(rhs)),
+                _ => Weight::Div(Box::new(lhs), Box::new(rhs)),
+            });
+        }
+
+        if self.try_keyword("DAYS") {
+            self.expect_char('(')?;
+            let inner = self.parse_weight()?;
+            self.expect_char(')')?;
+
+            return Ok(Weight::Days(Box::new(inner)));
+        }
+
+        if self.try_keyword("KB_ALLOCATED") {
+            return Ok(Weight::KbAllocated);
+        }
+
+        if self.try_keyword("FILE_SIZE") {
+            return Ok(Weight::FileSize);
+        }
+
+        if self.try_keyword("ACCESS_TIME") {
+            return Ok(Weight::AccessTime);
+        }
+
+        if self.try_keyword("CURRENT_TIMESTAMP") {
+            return Ok(Weight::CurrentTimestamp);
+        }
+
+        self.parse_float().map(Weight::Literal)
+    }
+
+    fn parse_weight_op(&mut self) -> Result<char> {
+        self.skip_whitespace();
+
+        match self.advance() {
+            Some(c @ ('+' | '-' | '*' | '/')) => Ok(c),
+            Some(c) => Err(self.error(ParseErrorKind::Expected {
+                expected: "+, -, * or /",
+                found: c.to_string(),
+            })),
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn parse_float(&mut self) -> Result<f64> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+
+        if matches!(self.peek(), Some('-')) {
+            self.advance();
+        }
+
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.') {
+            self.advance();
+        }
+
+        let s: String = self.chars[start..self.pos].iter().collect();
+
+        s.parse().map_err(|_| {
+            self.error(ParseErrorKind::Expected {
+                expected: "a number",
+                found: s,
+            })
+        })
+    }
+
+    /// Consumes and returns `true` if the next non-whitespace character is
+    /// `expected`, otherwise leaves the parser position untouched.
+    fn try_char(&mut self, expected: char) -> bool {
+        let mark = self.mark();
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.advance();
+            true
+        } else {
+            self.reset(mark);
+            false
+        }
+    }
+
+    fn try_where(&mut self) -> Result<Option<Expr>> {
+        if self.try_keyword("WHERE") {
+            Ok(Some(self.parse_expr()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// `(VARCHAR(...) || ' ' || VARCHAR(...) || ...)`
+    fn parse_show_list(&mut self) -> Result<Vec<Show>> {
+        self.expect_char('(')?;
+
+        let mut show = Vec::new();
+        let mut unknown = Vec::new();
+
+        loop {
+            self.expect_keyword("VARCHAR")?;
+            self.expect_char('(')?;
+            let token = self.parse_ident()?;
+            self.expect_char(')')?;
+
+            match show_from_token(&token) {
+                Some(s) => show.push(s),
+                None => unknown.push(self.error(ParseErrorKind::UnknownShow(token))),
+            }
+
+            if !self.try_concat_separator() {
+                break;
+            }
+        }
+
+        self.expect_char(')')?;
+
+        if unknown.is_empty() {
+            Ok(show)
+        } else {
+            Err(self.error(ParseErrorKind::Many(unknown)))
+        }
+    }
+
+    /// `|| ' ' ||` between two `SHOW` columns.
+    fn try_concat_separator(&mut self) -> bool {
+        let mark = self.mark();
+
+        if self.expect_concat().is_ok()
+            && self.try_string().is_some()
+            && self.expect_concat().is_ok()
+        {
+            true
+        } else {
+            self.reset(mark);
+            false
+        }
+    }
+
+    /// `NOT (...)`, `(... AND/OR ...)`, `PATH_LIKE(...)`, `NAME LIKE ...`,
+    /// `FILESET_NAME = ...`, or an attribute comparison.
+    fn parse_expr(&mut self) -> Result<Expr> {
+        self.skip_whitespace();
+
+        if self.try_keyword("NOT") {
+            self.expect_char('(')?;
+            let inner = self.parse_expr()?;
+            self.expect_char(')')?;
+
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+
+        if self.peek() == Some('(') {
+            self.advance();
+            let lhs = self.parse_expr()?;
+            let and = self.try_keyword("AND");
+
+            if !and && !self.try_keyword("OR") {
+                let found = self.describe_next();
+                return Err(self.error(ParseErrorKind::Expected {
+                    expected: "AND or OR",
+                    found,
+                }));
+            }
+
+            let rhs = self.parse_expr()?;
+            self.expect_char(')')?;
+
+            return Ok(if and {
+                Expr::And(Box::new(lhs), Box::new(rhs))
+            } else {
+                Expr::Or(Box::new(lhs), Box::new(rhs))
+            });
+        }
+
+        if self.try_keyword("PATH_LIKE") {
+            self.expect_char('(')?;
+            let pattern = self.parse_string()?;
+            self.expect_char(')')?;
+
+            return Ok(Expr::PathLike(pattern));
+        }
+
+        if self.try_keyword("NAME") {
+            self.expect_keyword("LIKE")?;
+            return Ok(Expr::NameLike(self.parse_string()?));
+        }
+
+        if self.try_keyword("FILESET_NAME") {
+            self.expect_char('=')?;
+            return Ok(Expr::FilesetName(self.parse_string()?));
+        }
+
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr> {
+        let attribute = self.parse_attribute()?;
+        let op = self.parse_comparison_op()?;
+        let literal = self.parse_literal()?;
+
+        Ok(match op {
+            "=" => Expr::Eq(attribute, literal),
+            "<>" => Expr::Ne(attribute, literal),
+            "<=" => Expr::Le(attribute, literal),
+            "<" => Expr::Lt(attribute, literal),
+            ">=" => Expr::Ge(attribute, literal),
+            _ => Expr::Gt(attribute, literal),
+        })
+    }
+
+    fn parse_attribute(&mut self) -> Result<Attribute> {
+        if self.try_keyword("USER_ID") {
+            return Ok(Attribute::UserId);
+        }
+
+        if self.try_keyword("GROUP_ID") {
+            return Ok(Attribute::GroupId);
+        }
+
+        if self.try_keyword("FILE_SIZE") {
+            return Ok(Attribute::FileSize);
+        }
+
+        if self.try_keyword("KB_ALLOCATED") {
+            return Ok(Attribute::KbAllocated);
+        }
+
+        if self.try_keyword("MODIFICATION_TIME") {
+            return Ok(Attribute::ModificationTime);
+        }
+
+        if self.try_keyword("POOL_NAME") {
+            return Ok(Attribute::PoolName);
+        }
+
+        let found = self.describe_next();
+        Err(self.error(ParseErrorKind::Expected {
+            expected: "an attribute name",
+            found,
+        }))
+    }
+
+    fn parse_comparison_op(&mut self) -> Result<&'static str> {
+        self.skip_whitespace();
+
+        if self.try_str("<>") {
+            Ok("<>")
+        } else if self.try_str("<=") {
+            Ok("<=")
+        } else if self.try_str(">=") {
+            Ok(">=")
+        } else if self.try_char('=') {
+            Ok("=")
+        } else if self.try_char('<') {
+            Ok("<")
+        } else if self.try_char('>') {
+            Ok(">")
+        } else {
+            let found = self.describe_next();
+            Err(self.error(ParseErrorKind::Expected {
+                expected: "a comparison operator",
+                found,
+            }))
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('\'') {
+            Ok(Literal::Str(self.parse_string()?))
+        } else {
+            Ok(Literal::Int(self.parse_number()?))
+        }
+    }
+
+    /// Consumes and returns `true` if `s` follows (after skipping
+    /// whitespace), otherwise leaves the parser position untouched.
+    fn try_str(&mut self, s: &str) -> bool {
+        let mark = self.mark();
+        self.skip_whitespace();
+
+        for expected in s.chars() {
+            if self.advance() != Some(expected) {
+                self.reset(mark);
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn show_from_token(token: &str) -> Option<Show> {
+    Some(match token {
+        "MODE" => Show::Mode,
+        "NLINK" => Show::Nlink,
+        "FILE_SIZE" => Show::FileSize,
+        "KB_ALLOCATED" => Show::KbAllocated,
+        _ => return None,
+    })
+}
+
+// ----------------------------------------------------------------------------
+// tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use indoc::indoc;
+
+    #[test]
+    fn round_trip() {
+        let input = indoc! {"
+            RULE
+              EXTERNAL LIST 'size'
+              EXEC ''
+
+            RULE 'size'
+              LIST 'size'
+              DIRECTORIES_PLUS
+              SHOW(VARCHAR(MODE) || ' ' || VARCHAR(FILE_SIZE))
+              WHERE (USER_ID = 1000 AND NOT (GROUP_ID = 100))
+
+            RULE
+              MIGRATE
+              FROM POOL 'system'
+              TO POOL 'slow'
+              THRESHOLD(90,70,50)
+              WEIGHT(DAYS(ACCESS_TIME))
+              WHERE PATH_LIKE('%.log')
+
+            RULE
+              DELETE
+              WHERE FILE_SIZE > 1000
+
+            RULE
+              EXCLUDE
+              WHERE FILESET_NAME = 'root'
+            "
+        };
+
+        let policy = Policy::parse(input).unwrap();
+
+        let mut result: Vec<u8> = Vec::new();
+        policy.write(&mut result).unwrap();
+        let result = std::str::from_utf8(&result).unwrap();
+
+        assert_eq!(input, result);
+    }
+
+    #[test]
+    fn unknown_rule_keyword() {
+        let error = Policy::parse("RULE BOGUS").unwrap_err();
+
+        match error.kind {
+            ParseErrorKind::Many(errors) => {
+                assert_eq!(1, errors.len());
+                assert!(matches!(
+                    errors[0].kind,
+                    ParseErrorKind::UnknownRuleKeyword(ref token) if token == "BOGUS"
+                ));
+            }
+            other => panic!("expected ParseErrorKind::Many, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unknown_show_is_collected_not_fatal() {
+        let error = Policy::parse(
+            "RULE LIST 'x' SHOW(VARCHAR(MODE) || ' ' || VARCHAR(BOGUS))",
+        )
+        .unwrap_err();
+
+        match error.kind {
+            ParseErrorKind::Many(mut rule_errors) => {
+                assert_eq!(1, rule_errors.len());
+
+                match rule_errors.remove(0).kind {
+                    ParseErrorKind::Many(show_errors) => {
+                        assert_eq!(1, show_errors.len());
+                        assert!(matches!(
+                            show_errors[0].kind,
+                            ParseErrorKind::UnknownShow(ref token) if token == "BOGUS"
+                        ));
+                    }
+                    other => panic!("expected ParseErrorKind::Many, got {other:?}"),
+                }
+            }
+            other => panic!("expected ParseErrorKind::Many, got {other:?}"),
+        }
+    }
+}
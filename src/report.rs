@@ -0,0 +1,263 @@
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::os::unix::ffi::OsStringExt;
+use std::path::{Path, PathBuf};
+
+use crate::types::{Rule, RuleType, Show};
+
+/// Errors when reading a report file.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Opening the report file failed.
+    #[error("opening report file `{0}`: {1}")]
+    Open(PathBuf, io::Error),
+
+    /// Reading a line from the report file failed.
+    #[error("reading report file: {0}")]
+    Read(io::Error),
+
+    /// A line did not match the `InodeNumber GenNumber SnapshotId
+    /// <SHOW-string> -- <FullPathName>` record format.
+    #[error("malformed record: {0}")]
+    Malformed(String),
+}
+
+type Result<T> = ::std::result::Result<T, Error>;
+
+/// A single, typed `SHOW` column value.
+#[derive(Debug)]
+pub enum ShowValue {
+    /// `VARCHAR(MODE)`
+    Mode(String),
+
+    /// `VARCHAR(NLINK)`
+    Nlink(u64),
+
+    /// `VARCHAR(FILE_SIZE)`
+    FileSize(u64),
+
+    /// `VARCHAR(KB_ALLOCATED)`
+    KbAllocated(u64),
+}
+
+/// A single record from an `EXTERNAL LIST` report file emitted by
+/// `mmapplypolicy`.
+#[derive(Debug)]
+pub struct Record {
+    /// The file's inode number.
+    pub inode: u64,
+
+    /// The inode generation number.
+    pub generation: u64,
+
+    /// The id of the snapshot the file belongs to, or `0` for the active
+    /// file system.
+    pub snapshot_id: u64,
+
+    /// The columns produced by the rule's `SHOW(...)` clause, in order,
+    /// typed according to the `LIST` rule passed to
+    /// [`Report::open_with_rule`]. Empty when no rule was given, the rule
+    /// does not carry `SHOW` columns (e.g. an `EXTERNAL LIST` rule), or its
+    /// `SHOW` column count does not match the record.
+    pub show: Vec<ShowValue>,
+
+    /// The same columns as `show`, but as the raw strings found in the
+    /// report file.
+    pub raw_show: Vec<String>,
+
+    /// The file's path, percent-decoded.
+    pub path: PathBuf,
+}
+
+/// Iterator over the records of an `EXTERNAL LIST` report file.
+pub struct Report<'a, R> {
+    lines: io::Lines<BufReader<R>>,
+    shows: Option<&'a [Show]>,
+}
+
+impl<'a> Report<'a, File> {
+    /// Opens the report file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be opened.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open_with_rule(path, None)
+    }
+
+    /// Opens the report file at `path`, typing its `SHOW` columns according
+    /// to the `LIST` rule whose `SHOW` clause produced it. The
+    /// `EXTERNAL LIST` rule named in
+    /// [`crate::prelude::RunOutput::reports`] only names the report file;
+    /// it carries no `SHOW` columns of its own, and typing against it
+    /// leaves [`Record::show`] empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be opened.
+    pub fn open_with_rule(path: impl AsRef<Path>, rule: Option<&'a Rule>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let file =
+            File::open(path).map_err(|error| Error::Open(path.to_owned(), error))?;
+
+        Ok(Self::from_reader(file, rule))
+    }
+}
+
+impl<'a, R: Read> Report<'a, R> {
+    /// Reads records from an already-open `reader`, typing its `SHOW`
+    /// columns according to the `LIST` rule whose `SHOW` clause produced
+    /// it. See [`Self::open_with_rule`] for why this must be the `LIST`
+    /// rule, not the `EXTERNAL LIST` rule.
+    pub fn from_reader(reader: R, rule: Option<&'a Rule>) -> Self {
+        Self {
+            lines: BufReader::new(reader).lines(),
+            shows: rule.and_then(shows_of),
+        }
+    }
+}
+
+impl<R: Read> Iterator for Report<'_, R> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(error) => return Some(Err(Error::Read(error))),
+        };
+
+        Some(parse_line(&line, self.shows))
+    }
+}
+
+fn shows_of(rule: &Rule) -> Option<&[Show]> {
+    match &rule.1 {
+        RuleType::List(_, _, show, _) => Some(show),
+        RuleType::ExternalList(..)
+        | RuleType::Migrate(..)
+        | RuleType::Delete(..)
+        | RuleType::Exclude(..) => None,
+    }
+}
+
+fn parse_line(line: &str, shows: Option<&[Show]>) -> Result<Record> {
+    let (fields, path) = line
+        .split_once(" -- ")
+        .ok_or_else(|| Error::Malformed(line.to_owned()))?;
+
+    let mut fields = fields.split_whitespace();
+
+    let inode = next_u64(&mut fields, line)?;
+    let generation = next_u64(&mut fields, line)?;
+    let snapshot_id = next_u64(&mut fields, line)?;
+
+    let raw_show: Vec<String> = fields.map(ToOwned::to_owned).collect();
+
+    let show = match shows {
+        Some(shows) if shows.len() == raw_show.len() => raw_show
+            .iter()
+            .zip(shows)
+            .map(|(value, show)| typed_show(show, value))
+            .collect::<Result<Vec<_>>>()?,
+        _ => Vec::new(),
+    };
+
+    Ok(Record {
+        inode,
+        generation,
+        snapshot_id,
+        show,
+        raw_show,
+        path: decode_path(path),
+    })
+}
+
+fn next_u64(fields: &mut std::str::SplitWhitespace<'_>, line: &str) -> Result<u64> {
+    fields
+        .next()
+        .ok_or_else(|| Error::Malformed(line.to_owned()))?
+        .parse()
+        .map_err(|_| Error::Malformed(line.to_owned()))
+}
+
+fn typed_show(show: &Show, value: &str) -> Result<ShowValue> {
+    let parse_u64 = || value.parse().map_err(|_| Error::Malformed(value.to_owned()));
+
+    Ok(match show {
+        Show::Mode => ShowValue::Mode(value.to_owned()),
+        Show::Nlink => ShowValue::Nlink(parse_u64()?),
+        Show::FileSize => ShowValue::FileSize(parse_u64()?),
+        Show::KbAllocated => ShowValue::KbAllocated(parse_u64()?),
+    })
+}
+
+/// Decodes the `%XX` percent-encoding GPFS uses for spaces, newlines, and
+/// other non-printable bytes in file-list path names.
+fn decode_path(encoded: &str) -> PathBuf {
+    let bytes = encoded.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..=i + 2]).ok();
+
+            if let Some(byte) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    PathBuf::from(OsString::from_vec(out))
+}
+
+// ----------------------------------------------------------------------------
+// tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::types::{DirectoriesPlus, Name};
+
+    #[test]
+    fn decode_percent_encoded_path() {
+        let line = "1 0 0 -- /data/a%20b%0Ac.txt";
+        let record = parse_line(line, None).unwrap();
+
+        assert_eq!(Path::new("/data/a b\nc.txt"), record.path);
+    }
+
+    #[test]
+    fn typed_show_columns() {
+        let rule = Rule::from(RuleType::List(
+            Name("size".into()),
+            DirectoriesPlus(false),
+            vec![Show::Mode, Show::FileSize],
+            None,
+        ));
+
+        let line = "7 0 0 rwx 12345 -- /data/file";
+        let record = parse_line(line, shows_of(&rule)).unwrap();
+
+        assert_eq!(7, record.inode);
+        assert!(matches!(record.show[0], ShowValue::Mode(ref mode) if mode == "rwx"));
+        assert!(matches!(record.show[1], ShowValue::FileSize(12345)));
+    }
+
+    #[test]
+    fn malformed_line_without_separator() {
+        let error = parse_line("no separator here", None).unwrap_err();
+
+        assert!(matches!(error, Error::Malformed(_)));
+    }
+}
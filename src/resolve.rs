@@ -0,0 +1,181 @@
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+use libc::{c_char, gid_t, group, passwd, uid_t};
+
+use crate::types::{Expr, Where};
+
+/// A lookup buffer big enough for `getpwnam_r`/`getgrnam_r` on any system
+/// this crate targets; both fail with `ERANGE` if it is too small, which
+/// surfaces as [`Error::Lookup`].
+const LOOKUP_BUF_LEN: usize = 16 * 1024;
+
+/// Error resolving a user or group name to a numeric id.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// No user exists with the given name.
+    #[error("no such user: `{0}`")]
+    UnknownUser(String),
+
+    /// No group exists with the given name.
+    #[error("no such group: `{0}`")]
+    UnknownGroup(String),
+
+    /// The name contains a nul byte and cannot be passed to libc.
+    #[error("invalid name `{0}`: contains a nul byte")]
+    InvalidName(String),
+
+    /// The underlying `getpwnam_r`/`getgrnam_r` call failed.
+    #[error("looking up `{0}`: {1}")]
+    Lookup(String, std::io::Error),
+}
+
+type Result<T> = ::std::result::Result<T, Error>;
+
+/// Resolving names.
+impl Where {
+    /// Returns a `USER_ID` filter for `name`, which may be a user name or a
+    /// numeric uid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` is not numeric and no such user exists.
+    pub fn user_by_name(name: &str) -> Result<Expr> {
+        if let Ok(uid) = name.parse() {
+            return Ok(Self::User(uid).into());
+        }
+
+        Ok(Self::User(lookup_uid(name)?).into())
+    }
+
+    /// Returns a `GROUP_ID` filter for `name`, which may be a group name or
+    /// a numeric gid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` is not numeric and no such group exists.
+    pub fn group_by_name(name: &str) -> Result<Expr> {
+        if let Ok(gid) = name.parse() {
+            return Ok(Self::Group(gid).into());
+        }
+
+        Ok(Self::Group(lookup_gid(name)?).into())
+    }
+}
+
+// `getpwnam_r` and `getgrnam_r` are the only way to resolve a user or group
+// name without shelling out, and they are inherently unsafe FFI calls; this
+// function and `lookup_gid` are the narrowly-scoped exceptions to the
+// crate-wide `deny(unsafe_code)`.
+#[allow(unsafe_code)]
+fn lookup_uid(name: &str) -> Result<uid_t> {
+    let c_name = CString::new(name).map_err(|_| Error::InvalidName(name.to_owned()))?;
+
+    let mut pwd = MaybeUninit::<passwd>::uninit();
+    let mut buf = vec![0 as c_char; LOOKUP_BUF_LEN];
+    let mut result: *mut passwd = std::ptr::null_mut();
+
+    let rc = unsafe {
+        libc::getpwnam_r(
+            c_name.as_ptr(),
+            pwd.as_mut_ptr(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            &raw mut result,
+        )
+    };
+
+    if result.is_null() {
+        return Err(if rc == 0 {
+            Error::UnknownUser(name.to_owned())
+        } else {
+            Error::Lookup(name.to_owned(), std::io::Error::from_raw_os_error(rc))
+        });
+    }
+
+    // SAFETY: `result` is non-null, so `getpwnam_r` initialized `pwd`.
+    Ok(unsafe { pwd.assume_init() }.pw_uid)
+}
+
+#[allow(unsafe_code)]
+fn lookup_gid(name: &str) -> Result<gid_t> {
+    let c_name = CString::new(name).map_err(|_| Error::InvalidName(name.to_owned()))?;
+
+    let mut grp = MaybeUninit::<group>::uninit();
+    let mut buf = vec![0 as c_char; LOOKUP_BUF_LEN];
+    let mut result: *mut group = std::ptr::null_mut();
+
+    let rc = unsafe {
+        libc::getgrnam_r(
+            c_name.as_ptr(),
+            grp.as_mut_ptr(),
+            buf.as_mut_ptr(),
+            buf.len(),
+            &raw mut result,
+        )
+    };
+
+    if result.is_null() {
+        return Err(if rc == 0 {
+            Error::UnknownGroup(name.to_owned())
+        } else {
+            Error::Lookup(name.to_owned(), std::io::Error::from_raw_os_error(rc))
+        });
+    }
+
+    // SAFETY: `result` is non-null, so `getgrnam_r` initialized `grp`.
+    Ok(unsafe { grp.assume_init() }.gr_gid)
+}
+
+// ----------------------------------------------------------------------------
+// tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::types::{Attribute, Literal};
+
+    #[test]
+    fn user_by_name_falls_back_to_numeric() {
+        let filter = Where::user_by_name("1000").unwrap();
+
+        assert!(matches!(
+            filter,
+            Expr::Eq(Attribute::UserId, Literal::Int(1000))
+        ));
+    }
+
+    #[test]
+    fn group_by_name_falls_back_to_numeric() {
+        let filter = Where::group_by_name("100").unwrap();
+
+        assert!(matches!(
+            filter,
+            Expr::Eq(Attribute::GroupId, Literal::Int(100))
+        ));
+    }
+
+    #[test]
+    fn user_by_name_unknown_name() {
+        let error = Where::user_by_name("no-such-user-mmpolicy-rs-test").unwrap_err();
+
+        assert!(matches!(error, Error::UnknownUser(_)));
+    }
+
+    #[test]
+    fn group_by_name_unknown_name() {
+        let error = Where::group_by_name("no-such-group-mmpolicy-rs-test").unwrap_err();
+
+        assert!(matches!(error, Error::UnknownGroup(_)));
+    }
+
+    #[test]
+    fn user_by_name_rejects_nul_byte() {
+        let error = Where::user_by_name("bad\0name").unwrap_err();
+
+        assert!(matches!(error, Error::InvalidName(_)));
+    }
+}
@@ -1,7 +1,10 @@
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
 use crate::types::{Policy, RuleType};
 
@@ -32,6 +35,40 @@ pub enum Error {
 
 type Result<T> = ::std::result::Result<T, Error>;
 
+/// Invoked with each line of `mmapplypolicy`'s output as it is produced,
+/// when running with [`OutputMode::Capture`].
+pub type LineCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// How to handle `mmapplypolicy`'s stdout and stderr while it runs.
+#[derive(Clone)]
+pub enum OutputMode {
+    /// Discard stdout, as with `-L 0`. Stderr still goes to this process's
+    /// stderr.
+    Discard,
+
+    /// Let `mmapplypolicy` write directly to this process's stderr.
+    Inherit,
+
+    /// Capture output line by line, returning every line in
+    /// [`RunOutput::lines`] once `run` completes. If `callback` is set, it
+    /// is additionally invoked with each line as it arrives, enabling
+    /// progress reporting while the run is still in progress.
+    Capture(Option<LineCallback>),
+}
+
+impl std::fmt::Debug for OutputMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Discard => f.write_str("Discard"),
+            Self::Inherit => f.write_str("Inherit"),
+            Self::Capture(callback) => f
+                .debug_tuple("Capture")
+                .field(&callback.as_ref().map(|_| ".."))
+                .finish(),
+        }
+    }
+}
+
 /// Options for running `mmapplypolicy`.
 #[derive(Clone, Debug, Default)]
 pub struct Options {
@@ -53,6 +90,27 @@ pub struct Options {
 
     /// Returns the level of information displayed used with `-L`.
     pub information_level: Option<String>,
+
+    /// Returns how to handle `mmapplypolicy`'s output. Defaults to `None`,
+    /// which keeps the previous behavior: stdout is discarded for `-L 0`,
+    /// spliced onto this process's stderr for any other `-L` level, and
+    /// left on this process's stdout when no level is set.
+    pub output: Option<OutputMode>,
+}
+
+/// Result of a successful [`Policy::run`].
+#[derive(Debug, Default)]
+pub struct RunOutput {
+    /// Paths to the `list.<name>` report files named by `EXTERNAL LIST`
+    /// rules. To read one back with typed `SHOW` columns via
+    /// [`crate::prelude::Report::open_with_rule`], pass the `LIST` rule
+    /// with the matching name, not this `EXTERNAL LIST` rule itself.
+    pub reports: Vec<PathBuf>,
+
+    /// Lines captured from `mmapplypolicy`'s stdout and stderr, in the
+    /// order they were received. Only populated when
+    /// [`Options::output`] is [`OutputMode::Capture`].
+    pub lines: Vec<String>,
 }
 
 impl Policy {
@@ -63,13 +121,17 @@ impl Policy {
     /// - creating the policy file
     /// - writing to the policy file
     /// - running the `mmapplypolicy` command
+    // Building up the `mmapplypolicy` invocation one optional flag at a time
+    // is inherently linear; splitting it up would scatter that mapping
+    // across several functions without making it any clearer.
+    #[allow(clippy::too_many_lines)]
     pub fn run(
         &self,
         dev_or_dir: impl AsRef<OsStr>,
         policy_path: impl AsRef<Path>,
         file_list_prefix: Option<&Path>,
         options: &Options,
-    ) -> Result<Vec<PathBuf>> {
+    ) -> Result<RunOutput> {
         if file_list_prefix.map_or(false, |prefix| {
             !prefix.is_dir() && prefix.file_name().is_none()
         }) {
@@ -99,12 +161,29 @@ impl Policy {
 
         if let Some(information_level) = &options.information_level {
             mmapplypolicy.arg("-L").arg(information_level);
+        }
 
-            if information_level == "0" {
+        match &options.output {
+            Some(OutputMode::Discard) => {
                 mmapplypolicy.stdout(Stdio::null());
-            } else {
+            }
+
+            Some(OutputMode::Inherit) => {
                 mmapplypolicy.stdout(std::io::stderr());
             }
+
+            Some(OutputMode::Capture(_)) => {
+                mmapplypolicy.stdout(Stdio::piped());
+                mmapplypolicy.stderr(Stdio::piped());
+            }
+
+            None => {
+                if options.information_level.as_deref() == Some("0") {
+                    mmapplypolicy.stdout(Stdio::null());
+                } else if options.information_level.is_some() {
+                    mmapplypolicy.stdout(std::io::stderr());
+                }
+            }
         }
 
         if let Some(choice_algorithm) = &options.choice_algorithm {
@@ -136,14 +215,22 @@ impl Policy {
             Error::ApplyPolicy("`mmapplypolicy` failed to start".into(), error)
         })?;
 
-        let mmapplypolicy = mmapplypolicy.wait().map_err(|error| {
-            Error::ApplyPolicy(
-                "failed waiting on `mmapplypolicy`".into(),
-                error,
-            )
-        })?;
+        let callback = match &options.output {
+            Some(OutputMode::Capture(callback)) => callback.clone(),
+            _ => None,
+        };
 
-        if mmapplypolicy.success() {
+        let (status, lines) = if let Some(OutputMode::Capture(_)) = &options.output {
+            capture_output(&mut mmapplypolicy, callback.as_ref())?
+        } else {
+            let status = mmapplypolicy.wait().map_err(|error| {
+                Error::ApplyPolicy("failed waiting on `mmapplypolicy`".into(), error)
+            })?;
+
+            (status, Vec::new())
+        };
+
+        if status.success() {
             let reports = self
                 .rules
                 .iter()
@@ -167,16 +254,19 @@ impl Policy {
                         })
                     }
 
-                    RuleType::List(_, _, _, _) => None,
+                    RuleType::List(..)
+                    | RuleType::Migrate(..)
+                    | RuleType::Delete(..)
+                    | RuleType::Exclude(..) => None,
                 })
                 .collect();
 
-            Ok(reports)
+            Ok(RunOutput { reports, lines })
         } else {
             let mut message = String::new();
             message.push_str("`mmapplypolicy` failed");
 
-            if let Some(rc) = mmapplypolicy.code() {
+            if let Some(rc) = status.code() {
                 message.push_str(&format!(" with exit status {rc}"));
             };
 
@@ -184,3 +274,115 @@ impl Policy {
         }
     }
 }
+
+/// Reads `child`'s piped stdout and stderr line by line while it runs,
+/// invoking `callback` (if any) with each line as it arrives, and returns
+/// the exit status together with every line collected.
+fn capture_output(
+    child: &mut std::process::Child,
+    callback: Option<&LineCallback>,
+) -> Result<(ExitStatus, Vec<String>)> {
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let (tx, rx) = mpsc::channel();
+
+    let mut handles = Vec::new();
+
+    if let Some(stdout) = stdout {
+        let tx = tx.clone();
+        handles.push(thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(std::result::Result::ok) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+
+    if let Some(stderr) = stderr {
+        let tx = tx.clone();
+        handles.push(thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(std::result::Result::ok) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+
+    drop(tx);
+
+    let mut lines = Vec::new();
+
+    for line in rx {
+        if let Some(callback) = callback {
+            callback(&line);
+        }
+
+        lines.push(line);
+    }
+
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    let status = child.wait().map_err(|error| {
+        Error::ApplyPolicy("failed waiting on `mmapplypolicy`".into(), error)
+    })?;
+
+    Ok((status, lines))
+}
+
+// ----------------------------------------------------------------------------
+// tests
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    fn spawn_piped(shell_command: &str) -> std::process::Child {
+        Command::new("sh")
+            .arg("-c")
+            .arg(shell_command)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap()
+    }
+
+    #[test]
+    fn capture_output_collects_stdout_and_stderr() {
+        let mut child = spawn_piped("echo out-line; echo err-line 1>&2");
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let callback: LineCallback = {
+            let seen = Arc::clone(&seen);
+            Arc::new(move |line: &str| seen.lock().unwrap().push(line.to_owned()))
+        };
+
+        let (status, mut lines) = capture_output(&mut child, Some(&callback)).unwrap();
+
+        assert!(status.success());
+
+        lines.sort();
+        assert_eq!(vec!["err-line".to_string(), "out-line".to_string()], lines);
+
+        let mut seen = seen.lock().unwrap().clone();
+        seen.sort();
+        assert_eq!(lines, seen);
+    }
+
+    #[test]
+    fn capture_output_without_callback() {
+        let mut child = spawn_piped("echo only-line");
+
+        let (status, lines) = capture_output(&mut child, None).unwrap();
+
+        assert!(status.success());
+        assert_eq!(vec!["only-line".to_string()], lines);
+    }
+}
@@ -59,14 +59,166 @@ pub enum Show {
     KbAllocated,
 }
 
-/// Filter.
+/// Attribute usable on the left-hand side of a comparison in an [`Expr`].
+#[derive(Debug, Clone, Copy)]
+pub enum Attribute {
+    /// `USER_ID`
+    UserId,
+
+    /// `GROUP_ID`
+    GroupId,
+
+    /// `FILE_SIZE`
+    FileSize,
+
+    /// `KB_ALLOCATED`
+    KbAllocated,
+
+    /// `MODIFICATION_TIME`
+    ModificationTime,
+
+    /// `POOL_NAME`
+    PoolName,
+}
+
+/// Value compared against an [`Attribute`] in an [`Expr`].
+#[derive(Debug)]
+pub enum Literal {
+    /// A numeric literal.
+    Int(i64),
+
+    /// A quoted string literal.
+    Str(String),
+}
+
+/// Filter expression used in a `WHERE` clause.
+#[derive(Debug)]
+pub enum Expr {
+    /// `({0} AND {1})`
+    And(Box<Self>, Box<Self>),
+
+    /// `({0} OR {1})`
+    Or(Box<Self>, Box<Self>),
+
+    /// `NOT ({0})`
+    Not(Box<Self>),
+
+    /// `{0} = {1}`
+    Eq(Attribute, Literal),
+
+    /// `{0} <> {1}`
+    Ne(Attribute, Literal),
+
+    /// `{0} < {1}`
+    Lt(Attribute, Literal),
+
+    /// `{0} <= {1}`
+    Le(Attribute, Literal),
+
+    /// `{0} > {1}`
+    Gt(Attribute, Literal),
+
+    /// `{0} >= {1}`
+    Ge(Attribute, Literal),
+
+    /// `PATH_LIKE('{0}')`
+    PathLike(String),
+
+    /// `NAME LIKE '{0}'`
+    NameLike(String),
+
+    /// `FILESET_NAME = '{0}'`
+    FilesetName(String),
+}
+
+impl Expr {
+    /// Equivalent to `USER_ID = {uid}`.
+    #[must_use]
+    pub fn user(uid: uid_t) -> Self {
+        Self::Eq(Attribute::UserId, Literal::Int(i64::from(uid)))
+    }
+
+    /// Equivalent to `GROUP_ID = {gid}`.
+    #[must_use]
+    pub fn group(gid: gid_t) -> Self {
+        Self::Eq(Attribute::GroupId, Literal::Int(i64::from(gid)))
+    }
+}
+
+/// Convenience constructors kept around from before [`Expr`] existed.
 #[derive(Debug)]
 pub enum Where {
-    /// `WHERE GROUP_ID = {0}`
+    /// Equivalent to [`Expr::user`].
+    User(uid_t),
+
+    /// Equivalent to [`Expr::group`].
     Group(gid_t),
+}
 
-    /// `WHERE USER_ID = {0}`
-    User(uid_t),
+impl From<Where> for Expr {
+    fn from(filter: Where) -> Self {
+        match filter {
+            Where::User(uid) => Self::user(uid),
+            Where::Group(gid) => Self::group(gid),
+        }
+    }
+}
+
+/// Source pool for `RULE MIGRATE ... FROM POOL`.
+#[derive(Debug)]
+pub struct FromPool(pub String);
+
+/// Destination pool for `RULE MIGRATE ... TO POOL`.
+#[derive(Debug)]
+pub struct ToPool(pub String);
+
+/// `THRESHOLD(high,low[,premigrate])` occupancy percentages that trigger a
+/// `MIGRATE` or `DELETE` rule.
+#[derive(Debug)]
+pub struct Threshold {
+    /// Start the rule once the pool reaches this percentage occupied.
+    pub high: u8,
+
+    /// Run the rule until the pool drops to this percentage occupied.
+    pub low: u8,
+
+    /// For `MIGRATE`, the percentage at which premigration starts.
+    pub premigrate: Option<u8>,
+}
+
+/// Typed expression for the `WEIGHT(...)` criterion GPFS uses to order
+/// candidate files. `THRESHOLD(...)` is modeled separately by [`Threshold`].
+#[derive(Debug)]
+pub enum Weight {
+    /// `KB_ALLOCATED`
+    KbAllocated,
+
+    /// `FILE_SIZE`
+    FileSize,
+
+    /// `ACCESS_TIME`
+    AccessTime,
+
+    /// `CURRENT_TIMESTAMP`
+    CurrentTimestamp,
+
+    /// A numeric literal.
+    Literal(f64),
+
+    /// `DAYS({0})`
+    Days(Box<Self>),
+
+    /// `({0} + {1})`
+    Add(Box<Self>, Box<Self>),
+
+    /// `({0} - {1})`
+    Sub(Box<Self>, Box<Self>),
+
+    /// `({0} * {1})`
+    Mul(Box<Self>, Box<Self>),
+
+    /// `({0} / {1})`
+    Div(Box<Self>, Box<Self>),
 }
 
 /// Policy rule types.
@@ -76,5 +228,20 @@ pub enum RuleType {
     ExternalList(Name, Exec),
 
     /// `RULE LIST`
-    List(Name, DirectoriesPlus, Vec<Show>, Option<Where>),
+    List(Name, DirectoriesPlus, Vec<Show>, Option<Expr>),
+
+    /// `RULE MIGRATE`
+    Migrate(
+        FromPool,
+        ToPool,
+        Option<Threshold>,
+        Option<Weight>,
+        Option<Expr>,
+    ),
+
+    /// `RULE DELETE`
+    Delete(Option<Threshold>, Option<Weight>, Option<Expr>),
+
+    /// `RULE EXCLUDE`
+    Exclude(Option<Expr>),
 }
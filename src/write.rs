@@ -1,6 +1,9 @@
 use std::io::{self, Write};
 
-use crate::types::{Exec, Name, Policy, Rule, RuleType, Show, Where};
+use crate::types::{
+    Attribute, Exec, Expr, FromPool, Literal, Name, Policy, Rule, RuleType, Show, Threshold,
+    ToPool, Weight,
+};
 
 impl Policy {
     /// Write the policy to `output`.
@@ -62,14 +65,28 @@ impl RuleType {
                     writeln!(output, "  SHOW({s})")?;
                 }
 
-                if let Some(filter) = filter {
-                    let s = match filter {
-                        Where::Group(group) => format!("GROUP_ID = {group}"),
-                        Where::User(user) => format!("USER_ID = {user}"),
-                    };
+                write_filter(filter.as_ref(), output)?;
+            }
 
-                    writeln!(output, "  WHERE {s}")?;
-                }
+            Self::Migrate(FromPool(from), ToPool(to), threshold, weight, filter) => {
+                writeln!(output, "  MIGRATE")?;
+                writeln!(output, "  FROM POOL '{from}'")?;
+                writeln!(output, "  TO POOL '{to}'")?;
+                write_threshold(threshold.as_ref(), output)?;
+                write_weight(weight.as_ref(), output)?;
+                write_filter(filter.as_ref(), output)?;
+            }
+
+            Self::Delete(threshold, weight, filter) => {
+                writeln!(output, "  DELETE")?;
+                write_threshold(threshold.as_ref(), output)?;
+                write_weight(weight.as_ref(), output)?;
+                write_filter(filter.as_ref(), output)?;
+            }
+
+            Self::Exclude(filter) => {
+                writeln!(output, "  EXCLUDE")?;
+                write_filter(filter.as_ref(), output)?;
             }
         }
 
@@ -77,6 +94,94 @@ impl RuleType {
     }
 }
 
+fn write_threshold(threshold: Option<&Threshold>, output: &mut impl Write) -> io::Result<()> {
+    let Some(threshold) = threshold else {
+        return Ok(());
+    };
+
+    write!(output, "  THRESHOLD({},{}", threshold.high, threshold.low)?;
+
+    if let Some(premigrate) = threshold.premigrate {
+        write!(output, ",{premigrate}")?;
+    }
+
+    writeln!(output, ")")
+}
+
+fn write_weight(weight: Option<&Weight>, output: &mut impl Write) -> io::Result<()> {
+    let Some(weight) = weight else {
+        return Ok(());
+    };
+
+    writeln!(output, "  WEIGHT({})", weight.render())
+}
+
+fn write_filter(filter: Option<&Expr>, output: &mut impl Write) -> io::Result<()> {
+    let Some(filter) = filter else {
+        return Ok(());
+    };
+
+    writeln!(output, "  WHERE {}", filter.render())
+}
+
+impl Expr {
+    fn render(&self) -> String {
+        match self {
+            Self::And(lhs, rhs) => format!("({} AND {})", lhs.render(), rhs.render()),
+            Self::Or(lhs, rhs) => format!("({} OR {})", lhs.render(), rhs.render()),
+            Self::Not(expr) => format!("NOT ({})", expr.render()),
+            Self::Eq(attr, lit) => format!("{} = {}", attr.as_str(), lit.render()),
+            Self::Ne(attr, lit) => format!("{} <> {}", attr.as_str(), lit.render()),
+            Self::Lt(attr, lit) => format!("{} < {}", attr.as_str(), lit.render()),
+            Self::Le(attr, lit) => format!("{} <= {}", attr.as_str(), lit.render()),
+            Self::Gt(attr, lit) => format!("{} > {}", attr.as_str(), lit.render()),
+            Self::Ge(attr, lit) => format!("{} >= {}", attr.as_str(), lit.render()),
+            Self::PathLike(pattern) => format!("PATH_LIKE('{pattern}')"),
+            Self::NameLike(pattern) => format!("NAME LIKE '{pattern}'"),
+            Self::FilesetName(name) => format!("FILESET_NAME = '{name}'"),
+        }
+    }
+}
+
+impl Attribute {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::UserId => "USER_ID",
+            Self::GroupId => "GROUP_ID",
+            Self::FileSize => "FILE_SIZE",
+            Self::KbAllocated => "KB_ALLOCATED",
+            Self::ModificationTime => "MODIFICATION_TIME",
+            Self::PoolName => "POOL_NAME",
+        }
+    }
+}
+
+impl Literal {
+    fn render(&self) -> String {
+        match self {
+            Self::Int(n) => n.to_string(),
+            Self::Str(s) => format!("'{s}'"),
+        }
+    }
+}
+
+impl Weight {
+    fn render(&self) -> String {
+        match self {
+            Self::KbAllocated => "KB_ALLOCATED".into(),
+            Self::FileSize => "FILE_SIZE".into(),
+            Self::AccessTime => "ACCESS_TIME".into(),
+            Self::CurrentTimestamp => "CURRENT_TIMESTAMP".into(),
+            Self::Literal(n) => n.to_string(),
+            Self::Days(expr) => format!("DAYS({})", expr.render()),
+            Self::Add(lhs, rhs) => format!("({} + {})", lhs.render(), rhs.render()),
+            Self::Sub(lhs, rhs) => format!("({} - {})", lhs.render(), rhs.render()),
+            Self::Mul(lhs, rhs) => format!("({} * {})", lhs.render(), rhs.render()),
+            Self::Div(lhs, rhs) => format!("({} / {})", lhs.render(), rhs.render()),
+        }
+    }
+}
+
 impl Show {
     const fn as_str(&self) -> &'static str {
         match self {
@@ -111,7 +216,7 @@ mod test {
             Name("size".into()),
             DirectoriesPlus(true),
             vec![Show::Mode, Show::Nlink, Show::FileSize, Show::KbAllocated],
-            Some(Where::User(1000)),
+            Some(Where::User(1000).into()),
         )));
 
         let mut result: Vec<u8> = Vec::new();
@@ -133,4 +238,62 @@ mod test {
 
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn migrate_delete_exclude() {
+        let mut policy = Policy::new("tier");
+
+        policy.rules.push(Rule::from(RuleType::Migrate(
+            FromPool("system".into()),
+            ToPool("slow".into()),
+            Some(Threshold {
+                high: 90,
+                low: 70,
+                premigrate: Some(50),
+            }),
+            Some(Weight::Days(Box::new(Weight::AccessTime))),
+            Some(Expr::PathLike("%.log".into())),
+        )));
+
+        policy.rules.push(Rule::from(RuleType::Delete(
+            Some(Threshold {
+                high: 95,
+                low: 80,
+                premigrate: None,
+            }),
+            None,
+            None,
+        )));
+
+        policy
+            .rules
+            .push(Rule::from(RuleType::Exclude(Some(Expr::FilesetName(
+                "root".into(),
+            )))));
+
+        let mut result: Vec<u8> = Vec::new();
+        policy.write(&mut result).unwrap();
+        let result = std::str::from_utf8(&result).unwrap();
+
+        let expected = indoc! {"
+            RULE
+              MIGRATE
+              FROM POOL 'system'
+              TO POOL 'slow'
+              THRESHOLD(90,70,50)
+              WEIGHT(DAYS(ACCESS_TIME))
+              WHERE PATH_LIKE('%.log')
+
+            RULE
+              DELETE
+              THRESHOLD(95,80)
+
+            RULE
+              EXCLUDE
+              WHERE FILESET_NAME = 'root'
+            "
+        };
+
+        assert_eq!(expected, result);
+    }
 }